@@ -2,7 +2,10 @@ use std::cmp::min;
 use std::fs;
 use std::io::{self, Stdout, Write};
 use std::path::{Path, PathBuf};
-use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
 
 use anyhow::{Context, Result, bail};
 use cascii_core_view::{
@@ -10,10 +13,27 @@ use cascii_core_view::{
 };
 use clap::Parser;
 use crossterm::cursor::{Hide, MoveTo, Show};
-use crossterm::event::{self, Event, KeyCode, KeyEventKind};
-use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Color, Print, ResetColor, SetBackgroundColor, SetForegroundColor};
 use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
 use crossterm::{execute, queue};
+use image::imageops::FilterType;
+use image::{Rgba, RgbaImage};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Source directory extensions that decode straight to a truecolor `HalfBlockFrame`,
+/// bypassing the `.cframe` pipeline entirely.
+const RASTER_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg"];
+
+/// Cap on how many columns a raster frame is resized to at load time, so a very wide
+/// terminal doesn't make a high-resolution source image get sampled at a pointlessly
+/// large size.
+const MAX_RASTER_COLUMNS: usize = 160;
+
+/// How far panning is allowed to overshoot a frame's true edge, so the last reachable
+/// column/row still leaves a sliver of blank space rather than sitting flush against it.
+const PAN_PADDING: usize = 2;
 
 #[derive(Debug, Parser)]
 #[command(
@@ -33,6 +53,193 @@ struct Args {
     /// Play once instead of looping
     #[arg(long, default_value_t = false)]
     once: bool,
+
+    /// Write an asciicast v2 recording of one full pass through the frames to this path
+    /// instead of entering interactive playback
+    #[arg(long)]
+    record: Option<PathBuf>,
+}
+
+/// A single rasterized terminal cell: the grapheme cluster to show (may be more than one
+/// `char`, e.g. an accented letter or emoji), its foreground/background color, and whether
+/// this cell is the second column of a wide (double-width) glyph printed at the column
+/// before it. Continuation cells are never queued for printing on their own; the terminal
+/// already covers them when the wide glyph's own column is printed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cell {
+    glyph: String,
+    fg: Color,
+    bg: Color,
+    continuation: bool,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            glyph: " ".to_string(),
+            fg: Color::Reset,
+            bg: Color::Reset,
+            continuation: false,
+        }
+    }
+}
+
+/// One playable unit of input: either the existing cframe/text art path, or a frame
+/// decoded straight from a raster image via half-block rendering.
+enum PlayableFrame {
+    Ascii(Frame),
+    Raster(HalfBlockFrame),
+}
+
+impl PlayableFrame {
+    fn has_color(&self) -> bool {
+        match self {
+            PlayableFrame::Ascii(frame) => frame.has_color(),
+            PlayableFrame::Raster(_) => true,
+        }
+    }
+}
+
+/// A truecolor frame sampled from a raster image using the Unicode upper-half-block
+/// technique: each output cell holds a foreground color (the top source pixel) and a
+/// background color (the bottom source pixel), doubling vertical resolution.
+struct HalfBlockFrame {
+    width: usize,
+    height: usize,
+    cells: Vec<(Color, Color)>,
+}
+
+impl HalfBlockFrame {
+    fn colors_at(&self, row: usize, col: usize) -> (Color, Color) {
+        self.cells[row * self.width + col]
+    }
+}
+
+/// Returns the natural (unclipped) width/height of a frame's own content, i.e. the
+/// dimensions panning scrolls across.
+fn frame_dimensions(frame: &PlayableFrame) -> (usize, usize) {
+    match frame {
+        PlayableFrame::Ascii(frame) => {
+            if let Some(cframe) = frame.cframe.as_ref() {
+                (cframe.width as usize, cframe.height as usize)
+            } else {
+                let lines: Vec<&str> = frame.content.lines().collect();
+                let width = lines.iter().map(|line| line.width()).max().unwrap_or(0);
+                (width, lines.len())
+            }
+        }
+        PlayableFrame::Raster(raster) => (raster.width, raster.height),
+    }
+}
+
+/// Furthest a pan offset may go along one axis: zero if the frame already fits in the
+/// viewport, otherwise the overflow plus `PAN_PADDING`.
+fn max_pan_offset(frame_dim: usize, viewport_dim: usize) -> usize {
+    if frame_dim > viewport_dim {
+        frame_dim - viewport_dim + PAN_PADDING
+    } else {
+        0
+    }
+}
+
+/// Where a frame lands on screen and which slice of its own content is visible: centered
+/// and offset-free when it fits the viewport, pinned to the edge and panned by `off_x`/
+/// `off_y` when it overflows.
+struct Viewport {
+    draw_width: usize,
+    draw_height: usize,
+    x_offset: usize,
+    y_offset: usize,
+    source_col: usize,
+    source_row: usize,
+}
+
+fn compute_viewport(
+    frame_width: usize,
+    frame_height: usize,
+    term_width: usize,
+    drawable_height: usize,
+    off_x: usize,
+    off_y: usize,
+) -> Viewport {
+    let draw_width = min(frame_width, term_width);
+    let draw_height = min(frame_height, drawable_height);
+
+    let (x_offset, source_col) = if frame_width > term_width {
+        (0, off_x.min(max_pan_offset(frame_width, term_width)))
+    } else {
+        ((term_width - draw_width) / 2, 0)
+    };
+    let (y_offset, source_row) = if frame_height > drawable_height {
+        (0, off_y.min(max_pan_offset(frame_height, drawable_height)))
+    } else {
+        ((drawable_height - draw_height) / 2, 0)
+    };
+
+    Viewport {
+        draw_width,
+        draw_height,
+        x_offset,
+        y_offset,
+        source_col,
+        source_row,
+    }
+}
+
+/// A full-screen grid of `Cell`s. Frames are rasterized into one of these rather than
+/// printed directly, so the new buffer can be diffed against the previous one and only
+/// the cells that actually changed get written to the terminal.
+struct ScreenBuffer {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl ScreenBuffer {
+    fn blank(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::default(); width * height],
+        }
+    }
+
+    fn get(&self, row: usize, col: usize) -> &Cell {
+        &self.cells[row * self.width + col]
+    }
+
+    /// Sets a single-column cell. For multi-column glyphs, use `set_glyph` instead so the
+    /// trailing column(s) are marked as continuations rather than left stale.
+    fn set(&mut self, row: usize, col: usize, glyph: &str, fg: Color, bg: Color) {
+        if row < self.height && col < self.width {
+            self.cells[row * self.width + col] = Cell {
+                glyph: glyph.to_string(),
+                fg,
+                bg,
+                continuation: false,
+            };
+        }
+    }
+
+    /// Writes a grapheme cluster at `(row, col)`, marking the following `width - 1` columns
+    /// as continuations of it (used for double-width glyphs like CJK characters and emoji).
+    fn set_glyph(&mut self, row: usize, col: usize, glyph: &str, fg: Color, bg: Color, width: usize) {
+        self.set(row, col, glyph, fg, bg);
+        for offset in 1..width {
+            if let Some(continuation) = col
+                .checked_add(offset)
+                .filter(|&c| row < self.height && c < self.width)
+                .map(|c| row * self.width + c)
+            {
+                self.cells[continuation] = Cell {
+                    glyph: String::new(),
+                    fg,
+                    bg,
+                    continuation: true,
+                };
+            }
+        }
+    }
 }
 
 struct TerminalGuard {
@@ -56,10 +263,65 @@ impl Drop for TerminalGuard {
     }
 }
 
+/// Unified events the player loop reacts to, merged from independent producer threads so that
+/// frame pacing never has to compete with input latency.
+enum PlayerEvent {
+    Key(KeyEvent),
+    Resize(u16, u16),
+    Tick,
+}
+
+/// Blocks on `event::read()` on a dedicated thread and forwards key/resize events onto `tx`,
+/// so a held key or a slow terminal read never stalls the frame clock.
+fn spawn_input_thread(tx: mpsc::Sender<PlayerEvent>) {
+    thread::spawn(move || loop {
+        match event::read() {
+            Ok(Event::Key(key)) => {
+                if tx.send(PlayerEvent::Key(key)).is_err() {
+                    break;
+                }
+            }
+            Ok(Event::Resize(width, height)) => {
+                if tx.send(PlayerEvent::Resize(width, height)).is_err() {
+                    break;
+                }
+            }
+            Ok(_) => {}
+            Err(_) => break,
+        }
+    });
+}
+
+/// Emits a `Tick` on `tx` at the cadence in `interval_ms`, re-reading it before every wait.
+/// A signal on `reschedule_rx` (sent by the main thread after an FPS change) interrupts the
+/// current wait immediately and restarts it with the fresh interval, rather than letting the
+/// old interval run out first.
+fn spawn_timer_thread(
+    tx: mpsc::Sender<PlayerEvent>,
+    interval_ms: Arc<AtomicU64>,
+    reschedule_rx: mpsc::Receiver<()>,
+) {
+    thread::spawn(move || loop {
+        let interval = Duration::from_millis(interval_ms.load(Ordering::Relaxed));
+        match reschedule_rx.recv_timeout(interval) {
+            Ok(()) => continue,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                if tx.send(PlayerEvent::Tick).is_err() {
+                    break;
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    });
+}
+
 fn main() -> Result<()> {
     let args = Args::parse();
-    let frames = load_frames(&args.directory)?;
-    let has_any_color = frames.iter().any(Frame::has_color);
+    let (term_width, term_height) = terminal::size().context("reading terminal size")?;
+    let raster_cols = min(term_width as usize, MAX_RASTER_COLUMNS);
+    let raster_rows = term_height.saturating_sub(1) as usize;
+    let frames = load_frames(&args.directory, raster_cols, raster_rows)?;
+    let has_any_color = frames.iter().any(PlayableFrame::has_color);
 
     let mut controller = AnimationController::new(args.fps);
     controller.set_frame_count(frames.len());
@@ -68,11 +330,15 @@ fn main() -> Result<()> {
     }
     controller.play();
 
+    if let Some(record_path) = args.record {
+        return record_playback(frames, has_any_color, controller, term_width, term_height, &record_path);
+    }
+
     run_player(frames, has_any_color, controller)
 }
 
 fn run_player(
-    frames: Vec<Frame>,
+    frames: Vec<PlayableFrame>,
     has_any_color: bool,
     mut controller: AnimationController,
 ) -> Result<()> {
@@ -82,7 +348,17 @@ fn run_player(
 
     let mut terminal = TerminalGuard::enter()?;
     let mut needs_redraw = true;
-    let mut last_tick = Instant::now();
+
+    let (tx, rx) = mpsc::channel();
+    spawn_input_thread(tx.clone());
+    let interval_ms = Arc::new(AtomicU64::new(controller.interval_ms() as u64));
+    let (reschedule_tx, reschedule_rx) = mpsc::channel();
+    spawn_timer_thread(tx, Arc::clone(&interval_ms), reschedule_rx);
+
+    let (mut term_width, mut term_height) = terminal::size().context("reading terminal size")?;
+    let mut previous = ScreenBuffer::blank(term_width as usize, term_height as usize);
+    let mut off_x: usize = 0;
+    let mut off_y: usize = 0;
 
     loop {
         if needs_redraw {
@@ -90,96 +366,121 @@ fn run_player(
             let frame = frames
                 .get(current_idx)
                 .context("current frame index out of bounds")?;
-            render_frame(
-                &mut terminal.stdout,
+
+            let mut current = ScreenBuffer::blank(term_width as usize, term_height as usize);
+            rasterize_frame(
+                &mut current,
                 frame,
                 &controller,
                 current_idx,
                 frames.len(),
                 has_any_color,
-            )?;
+                off_x,
+                off_y,
+            );
+            flush_diff(&mut terminal.stdout, &previous, &current)?;
             terminal
                 .stdout
                 .flush()
                 .context("flushing terminal output")?;
+            previous = current;
             needs_redraw = false;
         }
 
-        let wait_timeout = if controller.is_playing() {
-            let frame_duration = Duration::from_millis(controller.interval_ms() as u64);
-            frame_duration.saturating_sub(last_tick.elapsed())
-        } else {
-            Duration::from_millis(250)
+        let event = match rx.recv() {
+            Ok(event) => event,
+            Err(_) => break,
         };
 
-        if event::poll(wait_timeout).context("polling terminal events")? {
-            match event::read().context("reading terminal event")? {
-                Event::Resize(_, _) => {
-                    needs_redraw = true;
+        match event {
+            PlayerEvent::Resize(width, height) => {
+                term_width = width;
+                term_height = height;
+                queue!(terminal.stdout, Clear(ClearType::All)).context("clearing frame")?;
+                previous = ScreenBuffer::blank(term_width as usize, term_height as usize);
+                needs_redraw = true;
+            }
+            PlayerEvent::Key(key) => {
+                if key.kind == KeyEventKind::Release {
+                    continue;
                 }
-                Event::Key(key) => {
-                    if key.kind == KeyEventKind::Release {
-                        continue;
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => break,
+                    KeyCode::Char(' ') => {
+                        controller.toggle();
+                        needs_redraw = true;
                     }
-                    match key.code {
-                        KeyCode::Char('q') | KeyCode::Esc => break,
-                        KeyCode::Char(' ') => {
-                            controller.toggle();
-                            last_tick = Instant::now();
-                            needs_redraw = true;
-                        }
-                        KeyCode::Right => {
-                            controller.step_forward();
-                            needs_redraw = true;
-                        }
-                        KeyCode::Left => {
-                            controller.step_backward();
-                            needs_redraw = true;
-                        }
-                        KeyCode::Home => {
-                            controller.set_current_frame(0);
-                            needs_redraw = true;
-                        }
-                        KeyCode::End => {
-                            controller
-                                .set_current_frame(controller.frame_count().saturating_sub(1));
-                            needs_redraw = true;
-                        }
-                        KeyCode::Char('+') | KeyCode::Char('=') => {
-                            controller.set_fps(controller.fps().saturating_add(1));
-                            last_tick = Instant::now();
-                            needs_redraw = true;
-                        }
-                        KeyCode::Char('-') | KeyCode::Char('_') => {
-                            controller.set_fps(controller.fps().saturating_sub(1));
-                            last_tick = Instant::now();
-                            needs_redraw = true;
-                        }
-                        KeyCode::Char('l') => {
-                            let next_mode = match controller.loop_mode() {
-                                LoopMode::Loop => LoopMode::Once,
-                                LoopMode::Once => LoopMode::Loop,
-                            };
-                            controller.set_loop_mode(next_mode);
-                            needs_redraw = true;
-                        }
-                        _ => {}
+                    KeyCode::Left if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                        off_x = off_x.saturating_sub(1);
+                        needs_redraw = true;
+                    }
+                    KeyCode::Right if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                        let frame = frames
+                            .get(controller.current_frame())
+                            .context("current frame index out of bounds")?;
+                        let (frame_width, _) = frame_dimensions(frame);
+                        off_x = (off_x + 1).min(max_pan_offset(frame_width, term_width as usize));
+                        needs_redraw = true;
+                    }
+                    KeyCode::Up if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                        off_y = off_y.saturating_sub(1);
+                        needs_redraw = true;
+                    }
+                    KeyCode::Down if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                        let frame = frames
+                            .get(controller.current_frame())
+                            .context("current frame index out of bounds")?;
+                        let (_, frame_height) = frame_dimensions(frame);
+                        let drawable_height = (term_height as usize).saturating_sub(1);
+                        off_y = (off_y + 1).min(max_pan_offset(frame_height, drawable_height));
+                        needs_redraw = true;
+                    }
+                    KeyCode::Right => {
+                        controller.step_forward();
+                        needs_redraw = true;
+                    }
+                    KeyCode::Left => {
+                        controller.step_backward();
+                        needs_redraw = true;
+                    }
+                    KeyCode::Home => {
+                        controller.set_current_frame(0);
+                        needs_redraw = true;
                     }
+                    KeyCode::End => {
+                        controller.set_current_frame(controller.frame_count().saturating_sub(1));
+                        needs_redraw = true;
+                    }
+                    KeyCode::Char('+') | KeyCode::Char('=') => {
+                        controller.set_fps(controller.fps().saturating_add(1));
+                        interval_ms.store(controller.interval_ms() as u64, Ordering::Relaxed);
+                        let _ = reschedule_tx.send(());
+                        needs_redraw = true;
+                    }
+                    KeyCode::Char('-') | KeyCode::Char('_') => {
+                        controller.set_fps(controller.fps().saturating_sub(1));
+                        interval_ms.store(controller.interval_ms() as u64, Ordering::Relaxed);
+                        let _ = reschedule_tx.send(());
+                        needs_redraw = true;
+                    }
+                    KeyCode::Char('l') => {
+                        let next_mode = match controller.loop_mode() {
+                            LoopMode::Loop => LoopMode::Once,
+                            LoopMode::Once => LoopMode::Loop,
+                        };
+                        controller.set_loop_mode(next_mode);
+                        needs_redraw = true;
+                    }
+                    _ => {}
                 }
-                _ => {}
             }
-        }
-
-        if controller.is_playing() {
-            let frame_duration = Duration::from_millis(controller.interval_ms() as u64);
-            if last_tick.elapsed() >= frame_duration {
-                if controller.tick() {
-                    needs_redraw = true;
-                } else {
-                    // Ensure status line updates when playback transitions to Finished.
+            PlayerEvent::Tick => {
+                if controller.is_playing() {
+                    // The return value only distinguishes "advanced" from "finished"; the
+                    // status line reflects both, so either way we redraw.
+                    controller.tick();
                     needs_redraw = true;
                 }
-                last_tick = Instant::now();
             }
         }
     }
@@ -187,177 +488,306 @@ fn run_player(
     Ok(())
 }
 
-fn render_frame(
-    stdout: &mut Stdout,
-    frame: &Frame,
+/// Rasterizes one player frame (cframe/text art plus the status line) into `buf`.
+/// This never touches the terminal directly; `flush_diff` is what turns the buffer
+/// into actual `queue!`d writes, comparing against the previously rasterized frame.
+#[allow(clippy::too_many_arguments)]
+fn rasterize_frame(
+    buf: &mut ScreenBuffer,
+    frame: &PlayableFrame,
     controller: &AnimationController,
     frame_index: usize,
     total_frames: usize,
     has_any_color: bool,
-) -> Result<()> {
-    let (term_width, term_height) = terminal::size().context("reading terminal size")?;
-    let drawable_height = term_height.saturating_sub(1) as usize;
-    let term_width_usize = term_width as usize;
-
-    queue!(stdout, MoveTo(0, 0), Clear(ClearType::All)).context("clearing frame")?;
-
-    if let Some(cframe) = frame.cframe.as_ref() {
-        let frame_width = cframe.width as usize;
-        let frame_height = cframe.height as usize;
-        let draw_width = min(frame_width, term_width_usize);
-        let draw_height = min(frame_height, drawable_height);
-
-        let x_offset = term_width_usize.saturating_sub(draw_width) / 2;
-        let y_offset = drawable_height.saturating_sub(draw_height) / 2;
-
-        for row in 0..draw_height {
-            let mut col = 0usize;
-            while col < draw_width {
-                if cframe.should_skip(row, col) {
-                    col += 1;
-                    continue;
-                }
-
-                let start_col = col;
-                let (r, g, b) = cframe.rgb_at(row, col).unwrap_or((255, 255, 255));
-                let mut run = String::new();
-                run.push(cframe.char_at(row, col).unwrap_or(b' ') as char);
-                col += 1;
-
-                while col < draw_width {
-                    if cframe.should_skip(row, col) {
-                        break;
-                    }
-
-                    let next_color = cframe.rgb_at(row, col).unwrap_or((255, 255, 255));
-                    if next_color != (r, g, b) {
-                        break;
+    off_x: usize,
+    off_y: usize,
+) {
+    let term_width = buf.width;
+    let drawable_height = buf.height.saturating_sub(1);
+    let (frame_width, frame_height) = frame_dimensions(frame);
+    let viewport = compute_viewport(frame_width, frame_height, term_width, drawable_height, off_x, off_y);
+
+    match frame {
+        PlayableFrame::Ascii(frame) => {
+            if let Some(cframe) = frame.cframe.as_ref() {
+                for row in 0..viewport.draw_height {
+                    let source_row = viewport.source_row + row;
+                    for col in 0..viewport.draw_width {
+                        let source_col = viewport.source_col + col;
+                        if source_row >= frame_height || source_col >= frame_width {
+                            continue;
+                        }
+                        if cframe.should_skip(source_row, source_col) {
+                            continue;
+                        }
+                        let (r, g, b) = cframe.rgb_at(source_row, source_col).unwrap_or((255, 255, 255));
+                        let ch = cframe.char_at(source_row, source_col).unwrap_or(b' ') as char;
+                        let mut ch_buf = [0u8; 4];
+                        buf.set(
+                            viewport.y_offset + row,
+                            viewport.x_offset + col,
+                            ch.encode_utf8(&mut ch_buf),
+                            Color::Rgb { r, g, b },
+                            Color::Reset,
+                        );
                     }
-
-                    run.push(cframe.char_at(row, col).unwrap_or(b' ') as char);
-                    col += 1;
                 }
-
-                queue!(
-                    stdout,
-                    MoveTo((x_offset + start_col) as u16, (y_offset + row) as u16),
-                    SetForegroundColor(Color::Rgb { r, g, b }),
-                    Print(&run)
-                )
-                .context("drawing colored run")?;
+            } else {
+                rasterize_text_frame(buf, frame, &viewport);
             }
         }
-    } else {
-        draw_text_frame(stdout, frame, term_width_usize, drawable_height)?;
+        PlayableFrame::Raster(raster) => {
+            rasterize_raster_frame(buf, raster, &viewport);
+        }
     }
 
-    draw_status_line(
-        stdout,
+    rasterize_status_line(
+        buf,
         controller,
         frame_index,
         total_frames,
         has_any_color,
-        term_width,
-        term_height,
-    )?;
-
-    Ok(())
+        viewport.source_col,
+        viewport.source_row,
+    );
 }
 
-fn draw_text_frame(
-    stdout: &mut Stdout,
-    frame: &Frame,
-    term_width: usize,
-    drawable_height: usize,
-) -> Result<()> {
+fn rasterize_text_frame(buf: &mut ScreenBuffer, frame: &Frame, viewport: &Viewport) {
     let lines: Vec<&str> = frame.content.lines().collect();
-    let frame_height = lines.len();
-    let frame_width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
-    let draw_width = min(frame_width, term_width);
-    let draw_height = min(frame_height, drawable_height);
 
-    let x_offset = term_width.saturating_sub(draw_width) / 2;
-    let y_offset = drawable_height.saturating_sub(draw_height) / 2;
+    for row in 0..viewport.draw_height {
+        let source_row = viewport.source_row + row;
+        let Some(line) = lines.get(source_row) else {
+            continue;
+        };
 
-    for (row, line) in lines.iter().take(draw_height).enumerate() {
-        let bytes = line.as_bytes();
-        let row_width = min(bytes.len(), draw_width);
         let mut col = 0usize;
-
-        while col < row_width {
-            if bytes[col] == b' ' {
-                col += 1;
-                continue;
+        for grapheme in line.graphemes(true) {
+            let glyph_width = grapheme.width();
+            if col >= viewport.source_col + viewport.draw_width {
+                break;
             }
-
-            let start_col = col;
-            while col < row_width && bytes[col] != b' ' {
-                col += 1;
+            if glyph_width > 0 && col >= viewport.source_col && grapheme != " " {
+                let dest_col = col - viewport.source_col;
+                buf.set_glyph(
+                    viewport.y_offset + row,
+                    viewport.x_offset + dest_col,
+                    grapheme,
+                    Color::White,
+                    Color::Reset,
+                    glyph_width,
+                );
             }
-
-            let text = std::str::from_utf8(&bytes[start_col..col]).unwrap_or("");
-            queue!(
-                stdout,
-                MoveTo((x_offset + start_col) as u16, (y_offset + row) as u16),
-                SetForegroundColor(Color::White),
-                Print(text)
-            )
-            .context("drawing text run")?;
+            col += glyph_width;
         }
     }
+}
 
-    Ok(())
+/// Rasterizes a half-block image frame, printing `▀` per cell with the foreground set to
+/// the top source pixel and the background set to the bottom source pixel.
+fn rasterize_raster_frame(buf: &mut ScreenBuffer, raster: &HalfBlockFrame, viewport: &Viewport) {
+    for row in 0..viewport.draw_height {
+        let source_row = viewport.source_row + row;
+        if source_row >= raster.height {
+            continue;
+        }
+        for col in 0..viewport.draw_width {
+            let source_col = viewport.source_col + col;
+            if source_col >= raster.width {
+                continue;
+            }
+            let (fg, bg) = raster.colors_at(source_row, source_col);
+            buf.set(viewport.y_offset + row, viewport.x_offset + col, "\u{2580}", fg, bg);
+        }
+    }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn draw_status_line(
-    stdout: &mut Stdout,
+fn rasterize_status_line(
+    buf: &mut ScreenBuffer,
     controller: &AnimationController,
     frame_index: usize,
     total_frames: usize,
     has_any_color: bool,
-    term_width: u16,
-    term_height: u16,
-) -> Result<()> {
+    effective_pan_x: usize,
+    effective_pan_y: usize,
+) {
     let playback_state = format!("{:?}", controller.state()).to_lowercase();
     let loop_mode = match controller.loop_mode() {
         LoopMode::Loop => "loop",
         LoopMode::Once => "once",
     };
     let status = format!(
-        "frame {}/{} | {} | {} fps | {} | color:{} | [space] play/pause [←/→] step [+/-] fps [l] loop [q] quit",
+        "frame {}/{} | {} | {} fps | {} | color:{} | pan {},{} | [space] play/pause [←/→] step [shift+arrows] pan [+/-] fps [l] loop [q] quit",
         frame_index + 1,
         total_frames,
         playback_state,
         controller.fps(),
         loop_mode,
-        if has_any_color { "on" } else { "off" }
+        if has_any_color { "on" } else { "off" },
+        effective_pan_x,
+        effective_pan_y
     );
 
-    let status_line = truncate_to_width(&status, term_width as usize);
-    let y = term_height.saturating_sub(1);
-    let clear_line = " ".repeat(term_width as usize);
-
-    queue!(
-        stdout,
-        MoveTo(0, y),
-        SetForegroundColor(Color::DarkGrey),
-        Print(clear_line),
-        MoveTo(0, y),
-        Print(status_line),
-        ResetColor
+    let status_line = truncate_to_width(&status, buf.width);
+    let y = buf.height.saturating_sub(1);
+    for col in 0..buf.width {
+        buf.set(y, col, " ", Color::DarkGrey, Color::Reset);
+    }
+    let mut col = 0usize;
+    for grapheme in status_line.graphemes(true) {
+        buf.set_glyph(y, col, grapheme, Color::DarkGrey, Color::Reset, grapheme.width());
+        col += grapheme.width();
+    }
+}
+
+/// Truncates `input` to at most `width` display columns, measuring each grapheme cluster's
+/// actual terminal width rather than its `char` count so wide glyphs truncate correctly.
+fn truncate_to_width(input: &str, width: usize) -> String {
+    let mut result = String::new();
+    let mut used = 0usize;
+    for grapheme in input.graphemes(true) {
+        let glyph_width = grapheme.width();
+        if used + glyph_width > width {
+            break;
+        }
+        result.push_str(grapheme);
+        used += glyph_width;
+    }
+    result
+}
+
+/// Walks `previous` and `current` cell-by-cell and `queue!`s a `MoveTo` + `SetForegroundColor`
+/// + `SetBackgroundColor` + `Print` only for cells that changed, coalescing consecutive changed
+/// cells on the same row that share both colors into a single `Print` run. This is what keeps
+/// playback flicker-free: a full-screen clear is only ever issued on resize, everywhere else we
+/// write exactly the diff. Generic over the writer so the same encoding can target a live
+/// terminal or, for `--record`, an in-memory buffer captured into an asciicast event.
+fn flush_diff(writer: &mut impl Write, previous: &ScreenBuffer, current: &ScreenBuffer) -> Result<()> {
+    debug_assert_eq!(previous.width, current.width);
+    debug_assert_eq!(previous.height, current.height);
+
+    for row in 0..current.height {
+        let mut col = 0usize;
+        while col < current.width {
+            let cell = current.get(row, col);
+            if cell.continuation {
+                // Already covered by the wide glyph printed at the preceding column.
+                col += 1;
+                continue;
+            }
+            if cell == previous.get(row, col) {
+                col += 1;
+                continue;
+            }
+
+            let start_col = col;
+            let (fg, bg) = (cell.fg, cell.bg);
+            let mut run = String::new();
+            run.push_str(&cell.glyph);
+            col += 1;
+
+            while col < current.width {
+                let next = current.get(row, col);
+                if next.continuation || next == previous.get(row, col) || next.fg != fg || next.bg != bg
+                {
+                    break;
+                }
+                run.push_str(&next.glyph);
+                col += 1;
+            }
+
+            queue!(
+                writer,
+                MoveTo(start_col as u16, row as u16),
+                SetForegroundColor(fg),
+                SetBackgroundColor(bg),
+                Print(&run)
+            )
+            .context("drawing changed run")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders one full pass through `frames` to `path` as an asciicast v2
+/// (https://docs.asciinema.org/manual/asciicast/v2/) recording instead of interactive
+/// playback, reusing `rasterize_frame`/`flush_diff` so the captured escape sequences are
+/// exactly what a live terminal would have received. Always stops after a single pass,
+/// regardless of the controller's `LoopMode`.
+fn record_playback(
+    frames: Vec<PlayableFrame>,
+    has_any_color: bool,
+    mut controller: AnimationController,
+    term_width: u16,
+    term_height: u16,
+    path: &Path,
+) -> Result<()> {
+    let mut file = fs::File::create(path)
+        .with_context(|| format!("creating recording file {}", path.display()))?;
+    writeln!(
+        file,
+        "{{\"version\":2,\"width\":{},\"height\":{}}}",
+        term_width, term_height
     )
-    .context("drawing status line")?;
+    .context("writing asciicast header")?;
+
+    let mut previous = ScreenBuffer::blank(term_width as usize, term_height as usize);
+    let mut elapsed_s = 0.0_f64;
+
+    for (frame_index, frame) in frames.iter().enumerate() {
+        let mut current = ScreenBuffer::blank(term_width as usize, term_height as usize);
+        rasterize_frame(
+            &mut current,
+            frame,
+            &controller,
+            frame_index,
+            frames.len(),
+            has_any_color,
+            0,
+            0,
+        );
+
+        let mut output = Vec::new();
+        flush_diff(&mut output, &previous, &current)?;
+        let data = String::from_utf8(output).context("recorded frame output was not valid UTF-8")?;
+
+        writeln!(file, "[{}, \"o\", \"{}\"]", elapsed_s, json_escape(&data))
+            .context("writing asciicast event")?;
+
+        previous = current;
+        elapsed_s += controller.interval_ms() as f64 / 1000.0;
+        controller.tick();
+    }
 
     Ok(())
 }
 
-fn truncate_to_width(input: &str, width: usize) -> String {
-    input.chars().take(width).collect()
+/// Escapes a string for embedding in an asciicast event: the characters JSON itself
+/// requires, plus every other control byte as `\u00XX`, which is all that ANSI escape
+/// output ever contains beyond printable text.
+fn json_escape(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for ch in input.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
 }
 
-fn load_frames(directory: &Path) -> Result<Vec<Frame>> {
-    let cframe_paths = collect_frame_paths(directory, "cframe", false)?;
+fn load_frames(
+    directory: &Path,
+    raster_cols: usize,
+    raster_rows: usize,
+) -> Result<Vec<PlayableFrame>> {
+    let cframe_paths = collect_frame_paths(directory, &["cframe"], false)?;
     if !cframe_paths.is_empty() {
         let mut frames = Vec::with_capacity(cframe_paths.len());
         for path in cframe_paths {
@@ -366,43 +796,127 @@ fn load_frames(directory: &Path) -> Result<Vec<Frame>> {
                 .with_context(|| format!("parsing .cframe file {}", path.display()))?;
             let text = parse_cframe_text(&data)
                 .with_context(|| format!("extracting text from {}", path.display()))?;
-            frames.push(Frame::with_color(text, cframe));
+            frames.push(PlayableFrame::Ascii(Frame::with_color(text, cframe)));
+        }
+        return Ok(frames);
+    }
+
+    let txt_paths = collect_frame_paths(directory, &["txt"], true)?;
+    if !txt_paths.is_empty() {
+        let mut frames = Vec::with_capacity(txt_paths.len());
+        for txt_path in txt_paths {
+            let content = fs::read_to_string(&txt_path)
+                .with_context(|| format!("reading {}", txt_path.display()))?;
+            let content = normalize_frame_text(content);
+            let cframe_path = txt_path.with_extension("cframe");
+
+            if cframe_path.exists() {
+                let data = fs::read(&cframe_path)
+                    .with_context(|| format!("reading {}", cframe_path.display()))?;
+                let cframe = parse_cframe(&data)
+                    .with_context(|| format!("parsing .cframe file {}", cframe_path.display()))?;
+                frames.push(PlayableFrame::Ascii(Frame::with_color(content, cframe)));
+            } else {
+                frames.push(PlayableFrame::Ascii(Frame::text_only(content)));
+            }
         }
         return Ok(frames);
     }
 
-    let txt_paths = collect_frame_paths(directory, "txt", true)?;
-    if txt_paths.is_empty() {
+    let raster_paths = collect_frame_paths(directory, RASTER_EXTENSIONS, true)?;
+    if raster_paths.is_empty() {
         bail!(
-            "No frame files found in {} (expected .cframe or frame_*.txt)",
+            "No frame files found in {} (expected .cframe, frame_*.txt, or frame_*.png/jpg)",
             directory.display()
         );
     }
 
-    let mut frames = Vec::with_capacity(txt_paths.len());
-    for txt_path in txt_paths {
-        let content = fs::read_to_string(&txt_path)
-            .with_context(|| format!("reading {}", txt_path.display()))?;
-        let content = normalize_frame_text(content);
-        let cframe_path = txt_path.with_extension("cframe");
+    let mut frames = Vec::with_capacity(raster_paths.len());
+    for path in raster_paths {
+        frames.push(PlayableFrame::Raster(load_raster_frame(
+            &path,
+            raster_cols,
+            raster_rows,
+        )?));
+    }
 
-        if cframe_path.exists() {
-            let data = fs::read(&cframe_path)
-                .with_context(|| format!("reading {}", cframe_path.display()))?;
-            let cframe = parse_cframe(&data)
-                .with_context(|| format!("parsing .cframe file {}", cframe_path.display()))?;
-            frames.push(Frame::with_color(content, cframe));
-        } else {
-            frames.push(Frame::text_only(content));
+    Ok(frames)
+}
+
+/// Solid color used to pad the letterbox/pillarbox bars when a source image's aspect ratio
+/// doesn't match the target cell grid.
+const LETTERBOX: Rgba<u8> = Rgba([0, 0, 0, 255]);
+
+/// Decodes a PNG/JPEG via the `image` crate and fits it, aspect-correct, into a `draw_width`
+/// by `2 * draw_height` source pixel canvas (one pixel pair per output cell, for the
+/// foreground/background half-block split), letterboxing or pillarboxing with `LETTERBOX`
+/// rather than stretching the image to the terminal grid's own aspect ratio.
+fn load_raster_frame(path: &Path, draw_width: usize, draw_height: usize) -> Result<HalfBlockFrame> {
+    let draw_width = draw_width.max(1);
+    let draw_height = draw_height.max(1);
+    let canvas_width = draw_width as u32;
+    let canvas_height = (draw_height * 2) as u32;
+
+    let image = image::open(path).with_context(|| format!("decoding image {}", path.display()))?;
+    let (fitted_width, fitted_height) =
+        fit_within(image.width(), image.height(), canvas_width, canvas_height);
+    let fitted = image
+        .resize_exact(fitted_width, fitted_height, FilterType::Triangle)
+        .to_rgba8();
+
+    let x_pad = (canvas_width - fitted_width) / 2;
+    let y_pad = (canvas_height - fitted_height) / 2;
+
+    let mut cells = Vec::with_capacity(draw_width * draw_height);
+    for row in 0..draw_height {
+        for col in 0..draw_width {
+            let top = sample_fitted(&fitted, col as u32, (row * 2) as u32, x_pad, y_pad);
+            let bottom = sample_fitted(&fitted, col as u32, (row * 2 + 1) as u32, x_pad, y_pad);
+            cells.push((rgb_color(top), rgb_color(bottom)));
         }
     }
 
-    Ok(frames)
+    Ok(HalfBlockFrame {
+        width: draw_width,
+        height: draw_height,
+        cells,
+    })
+}
+
+/// Computes the largest `(width, height)` that fits within `max_width`x`max_height` while
+/// preserving `src_width`/`src_height`'s aspect ratio ("contain" fit).
+fn fit_within(src_width: u32, src_height: u32, max_width: u32, max_height: u32) -> (u32, u32) {
+    let scale = (max_width as f64 / src_width.max(1) as f64)
+        .min(max_height as f64 / src_height.max(1) as f64);
+    (
+        ((src_width as f64 * scale).round() as u32).clamp(1, max_width),
+        ((src_height as f64 * scale).round() as u32).clamp(1, max_height),
+    )
+}
+
+/// Samples `fitted` at canvas coordinates `(x, y)`, offset by the letterbox padding, returning
+/// `LETTERBOX` for any canvas pixel that falls outside the fitted image.
+fn sample_fitted(fitted: &RgbaImage, x: u32, y: u32, x_pad: u32, y_pad: u32) -> Rgba<u8> {
+    let (Some(src_x), Some(src_y)) = (x.checked_sub(x_pad), y.checked_sub(y_pad)) else {
+        return LETTERBOX;
+    };
+    if src_x >= fitted.width() || src_y >= fitted.height() {
+        return LETTERBOX;
+    }
+    *fitted.get_pixel(src_x, src_y)
+}
+
+fn rgb_color(pixel: Rgba<u8>) -> Color {
+    Color::Rgb {
+        r: pixel[0],
+        g: pixel[1],
+        b: pixel[2],
+    }
 }
 
 fn collect_frame_paths(
     directory: &Path,
-    extension: &str,
+    extensions: &[&str],
     require_frame_prefix: bool,
 ) -> Result<Vec<PathBuf>> {
     let entries = fs::read_dir(directory)
@@ -419,7 +933,7 @@ fn collect_frame_paths(
         let Some(ext) = path.extension().and_then(|ext| ext.to_str()) else {
             continue;
         };
-        if !ext.eq_ignore_ascii_case(extension) {
+        if !extensions.iter().any(|candidate| ext.eq_ignore_ascii_case(candidate)) {
             continue;
         }
 